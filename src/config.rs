@@ -0,0 +1,271 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+
+/// A key chord: a code plus the modifiers that must be held alongside it.
+pub type KeyBinding = (KeyCode, KeyModifiers);
+
+/// Every remappable action in `run_app`'s normal-mode match arm.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Up,
+    Down,
+    Switch,
+    Quit,
+    Filter,
+    Delete,
+    Create,
+    Rename,
+    Tab,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    PreviewUp,
+    PreviewDown,
+}
+
+pub struct KeyConfig {
+    up: Vec<KeyBinding>,
+    down: Vec<KeyBinding>,
+    switch: Vec<KeyBinding>,
+    quit: Vec<KeyBinding>,
+    filter: Vec<KeyBinding>,
+    delete: Vec<KeyBinding>,
+    create: Vec<KeyBinding>,
+    rename: Vec<KeyBinding>,
+    tab: Vec<KeyBinding>,
+    page_up: Vec<KeyBinding>,
+    page_down: Vec<KeyBinding>,
+    top: Vec<KeyBinding>,
+    bottom: Vec<KeyBinding>,
+    preview_up: Vec<KeyBinding>,
+    preview_down: Vec<KeyBinding>,
+}
+
+impl Default for KeyConfig {
+    fn default() -> Self {
+        let key = |c| (KeyCode::Char(c), KeyModifiers::NONE);
+        KeyConfig {
+            up: vec![(KeyCode::Up, KeyModifiers::NONE), key('k')],
+            down: vec![(KeyCode::Down, KeyModifiers::NONE), key('j')],
+            switch: vec![(KeyCode::Enter, KeyModifiers::NONE)],
+            quit: vec![key('q'), (KeyCode::Esc, KeyModifiers::NONE)],
+            filter: vec![key('/')],
+            delete: vec![key('d')],
+            create: vec![key('n')],
+            rename: vec![key('r')],
+            tab: vec![(KeyCode::Tab, KeyModifiers::NONE), (KeyCode::BackTab, KeyModifiers::SHIFT)],
+            page_up: vec![(KeyCode::PageUp, KeyModifiers::NONE)],
+            page_down: vec![(KeyCode::PageDown, KeyModifiers::NONE)],
+            top: vec![key('g')],
+            bottom: vec![key('G')],
+            preview_up: vec![(KeyCode::Char('u'), KeyModifiers::CONTROL)],
+            preview_down: vec![(KeyCode::Char('d'), KeyModifiers::CONTROL)],
+        }
+    }
+}
+
+impl KeyConfig {
+    /// Returns the action bound to this key chord, if any. Shift is ignored when matching
+    /// since terminals inconsistently report it alongside the letter it already capitalized
+    /// (e.g. `G`) -- Ctrl/Alt combos still have to match exactly.
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        let relevant = modifiers & !KeyModifiers::SHIFT;
+        let bound = |bindings: &[KeyBinding]| {
+            bindings.iter().any(|&(c, m)| c == code && m & !KeyModifiers::SHIFT == relevant)
+        };
+        if bound(&self.up) {
+            Some(Action::Up)
+        } else if bound(&self.down) {
+            Some(Action::Down)
+        } else if bound(&self.switch) {
+            Some(Action::Switch)
+        } else if bound(&self.quit) {
+            Some(Action::Quit)
+        } else if bound(&self.filter) {
+            Some(Action::Filter)
+        } else if bound(&self.delete) {
+            Some(Action::Delete)
+        } else if bound(&self.create) {
+            Some(Action::Create)
+        } else if bound(&self.rename) {
+            Some(Action::Rename)
+        } else if bound(&self.tab) {
+            Some(Action::Tab)
+        } else if bound(&self.page_up) {
+            Some(Action::PageUp)
+        } else if bound(&self.page_down) {
+            Some(Action::PageDown)
+        } else if bound(&self.top) {
+            Some(Action::Top)
+        } else if bound(&self.bottom) {
+            Some(Action::Bottom)
+        } else if bound(&self.preview_up) {
+            Some(Action::PreviewUp)
+        } else if bound(&self.preview_down) {
+            Some(Action::PreviewDown)
+        } else {
+            None
+        }
+    }
+
+    fn apply(&mut self, raw: RawKeyConfig) {
+        overlay(&mut self.up, raw.up);
+        overlay(&mut self.down, raw.down);
+        overlay(&mut self.switch, raw.switch);
+        overlay(&mut self.quit, raw.quit);
+        overlay(&mut self.filter, raw.filter);
+        overlay(&mut self.delete, raw.delete);
+        overlay(&mut self.create, raw.create);
+        overlay(&mut self.rename, raw.rename);
+        overlay(&mut self.tab, raw.tab);
+        overlay(&mut self.page_up, raw.page_up);
+        overlay(&mut self.page_down, raw.page_down);
+        overlay(&mut self.top, raw.top);
+        overlay(&mut self.bottom, raw.bottom);
+        overlay(&mut self.preview_up, raw.preview_up);
+        overlay(&mut self.preview_down, raw.preview_down);
+    }
+}
+
+/// Replaces `field` with the parsed bindings in `specs`, ignoring unparseable entries.
+/// Leaves `field` (the default) untouched when `specs` is absent or empty.
+fn overlay(field: &mut Vec<KeyBinding>, specs: Option<Vec<String>>) {
+    let Some(specs) = specs else { return };
+    let parsed: Vec<KeyBinding> = specs.iter().filter_map(|s| parse_binding(s)).collect();
+    if !parsed.is_empty() {
+        *field = parsed;
+    }
+}
+
+/// Parses a binding like `"j"`, `"down"`, or `"ctrl-d"` into a `KeyBinding`.
+fn parse_binding(spec: &str) -> Option<KeyBinding> {
+    let mut parts = spec.split(['-', '+']).collect::<Vec<_>>();
+    let base = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            _ => return None,
+        }
+    }
+
+    let code = match base.to_ascii_lowercase().as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "space" => KeyCode::Char(' '),
+        "backspace" => KeyCode::Backspace,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        _ => {
+            let mut chars = base.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(c)
+        }
+    };
+    Some((code, modifiers))
+}
+
+fn parse_color(name: &str) -> Option<Color> {
+    match name.to_ascii_lowercase().as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "white" => Some(Color::White),
+        "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        _ => None,
+    }
+}
+
+pub struct Config {
+    pub keys: KeyConfig,
+    pub highlight_style: Style,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            keys: KeyConfig::default(),
+            highlight_style: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct RawKeyConfig {
+    up: Option<Vec<String>>,
+    down: Option<Vec<String>>,
+    switch: Option<Vec<String>>,
+    quit: Option<Vec<String>>,
+    filter: Option<Vec<String>>,
+    delete: Option<Vec<String>>,
+    create: Option<Vec<String>>,
+    rename: Option<Vec<String>>,
+    tab: Option<Vec<String>>,
+    page_up: Option<Vec<String>>,
+    page_down: Option<Vec<String>>,
+    top: Option<Vec<String>>,
+    bottom: Option<Vec<String>>,
+    preview_up: Option<Vec<String>>,
+    preview_down: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Default)]
+struct RawStyle {
+    highlight_fg: Option<String>,
+    highlight_bold: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+struct RawConfig {
+    #[serde(default)]
+    keys: RawKeyConfig,
+    #[serde(default)]
+    style: RawStyle,
+}
+
+fn config_path() -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("gbs").join("config.toml"))
+}
+
+/// Loads `~/.config/gbs/config.toml`, falling back to the hardcoded defaults for any
+/// action or style not set (or if no file is present at all).
+pub fn load() -> Config {
+    let mut config = Config::default();
+
+    let Some(path) = config_path() else { return config };
+    let Ok(contents) = std::fs::read_to_string(path) else { return config };
+    let Ok(raw) = toml::from_str::<RawConfig>(&contents) else { return config };
+
+    config.keys.apply(raw.keys);
+    if let Some(name) = raw.style.highlight_fg {
+        if let Some(color) = parse_color(&name) {
+            config.highlight_style = config.highlight_style.fg(color);
+        }
+    }
+    if raw.style.highlight_bold == Some(false) {
+        config.highlight_style = config.highlight_style.remove_modifier(Modifier::BOLD);
+    }
+
+    config
+}
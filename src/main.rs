@@ -7,34 +7,166 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config as NucleoConfig, Matcher, Utf32Str};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
-    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, ListState, Tabs},
     Terminal,
 };
 
+mod config;
+use config::Action;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BranchKind {
+    Local,
+    Remote,
+}
+
 struct Branch {
     name: String,
     short_sha: String,
     is_current: bool,
+    kind: BranchKind,
+    author: String,
+    committer_date_relative: String,
+    subject: String,
+    body: String,
+}
+
+/// What a minibuffer prompt is being used to collect right now.
+#[derive(Clone, Copy)]
+enum PromptAction {
+    Create,
+    Rename,
+}
+
+/// Whether the picker is taking normal navigation keys, capturing a filter query, capturing a
+/// minibuffer prompt, or waiting on a delete confirmation.
+enum Mode {
+    Normal,
+    Filter,
+    Prompt(PromptAction),
+    ConfirmDelete { force: bool },
 }
 
 struct App {
     branches: Vec<Branch>,
     selected: usize,
+    mode: Mode,
+    filter: String,
+    tab: BranchKind,
+    /// Indices into `branches` that survive the current tab + filter, in display order.
+    visible: Vec<usize>,
+    /// Buffer for the current minibuffer prompt (new/renamed branch name).
+    prompt_input: String,
+    /// Last git stderr from a branch-lifecycle action, shown as a transient error line.
+    error: Option<String>,
+    /// Selection + scroll offset for the branch list, kept in sync with `selected` each frame.
+    list_state: ListState,
+    /// Height (in rows) the branch list was last rendered at, used to size Page Up/Down jumps.
+    visible_rows: usize,
+    /// Keybindings and colors, loaded from `~/.config/gbs/config.toml` if present.
+    cfg: config::Config,
+    /// Scroll offset into the commit-body preview, reset whenever the selected branch changes.
+    preview_scroll: u16,
+    /// Real index of the branch the preview was last drawn for, used to detect selection changes.
+    last_previewed: Option<usize>,
+}
+
+impl App {
+    fn new(branches: Vec<Branch>, cfg: config::Config) -> Self {
+        let mut app = App {
+            branches,
+            selected: 0,
+            mode: Mode::Normal,
+            filter: String::new(),
+            tab: BranchKind::Local,
+            visible: Vec::new(),
+            prompt_input: String::new(),
+            error: None,
+            list_state: ListState::default(),
+            visible_rows: 10,
+            cfg,
+            preview_scroll: 0,
+            last_previewed: None,
+        };
+        app.recompute_filter();
+        app
+    }
+
+    fn selected_branch(&self) -> Option<&Branch> {
+        self.visible.get(self.selected).map(|&i| &self.branches[i])
+    }
+
+    /// Reloads `branches` from git after a lifecycle action and re-applies the current filter.
+    fn reload(&mut self) -> Result<()> {
+        self.branches = load_branches()?;
+        self.recompute_filter();
+        Ok(())
+    }
+
+    fn toggle_tab(&mut self) {
+        self.tab = match self.tab {
+            BranchKind::Local => BranchKind::Remote,
+            BranchKind::Remote => BranchKind::Local,
+        };
+        self.recompute_filter();
+    }
+
+    /// Re-derives `visible` from the current tab and `filter`. Branches outside the active
+    /// tab are excluded outright; within the tab, fuzzy-matching and scoring happens the same
+    /// way as before tabs existed. Non-matches are dropped; matches are sorted by score
+    /// descending, ties kept in the original (committerdate) order since the sort is stable.
+    fn recompute_filter(&mut self) {
+        let in_tab = |i: &usize| self.branches[*i].kind == self.tab;
+
+        if self.filter.is_empty() {
+            self.visible = (0..self.branches.len()).filter(in_tab).collect();
+        } else {
+            let mut matcher = Matcher::new(NucleoConfig::DEFAULT);
+            let pattern = Pattern::parse(&self.filter, CaseMatching::Smart, Normalization::Smart);
+
+            let mut scored: Vec<(usize, u32)> = (0..self.branches.len())
+                .filter(in_tab)
+                .filter_map(|i| {
+                    let mut haystack_buf = Vec::new();
+                    let haystack = Utf32Str::new(&self.branches[i].name, &mut haystack_buf);
+                    pattern.score(haystack, &mut matcher).map(|score| (i, score))
+                })
+                .collect();
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+            self.visible = scored.into_iter().map(|(i, _)| i).collect();
+        }
+        self.selected = 0;
+    }
 }
 
 fn load_branches() -> Result<Vec<Branch>> {
+    let mut branches = load_refs("refs/heads", BranchKind::Local)?;
+    branches.extend(load_refs("refs/remotes", BranchKind::Remote)?);
+    Ok(branches)
+}
+
+// Field separator within a record and record separator between refs. Both are control
+// characters that can't appear in ref names or commit text, so they survive multi-line
+// commit bodies intact (unlike splitting on '\n').
+const FIELD_SEP: char = '\x1f';
+const RECORD_SEP: char = '\x1e';
+
+fn load_refs(refs: &str, kind: BranchKind) -> Result<Vec<Branch>> {
+    let format = format!(
+        "--format=%(refname:short){sep}%(objectname:short){sep}%(HEAD){sep}%(authorname){sep}\
+         %(committerdate:relative){sep}%(contents:subject){sep}%(contents:body){rec}",
+        sep = FIELD_SEP,
+        rec = RECORD_SEP,
+    );
     let output = Command::new("git")
-        .args(&[
-            "for-each-ref",
-            "--sort=-committerdate",
-            "--format=%(refname:short)|%(objectname:short)|%(HEAD)",
-            "refs/heads",
-        ])
+        .args(&["for-each-ref", "--sort=-committerdate", format.as_str(), refs])
         .output()?;
 
     if !output.status.success() {
@@ -43,55 +175,112 @@ fn load_branches() -> Result<Vec<Branch>> {
 
     let stdout = String::from_utf8(output.stdout)?;
     let branches = stdout
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(|line| {
-            let mut parts = line.split('|');
+        .split(RECORD_SEP)
+        .filter(|r| !r.trim().is_empty())
+        // remote symrefs like "origin/HEAD" aren't real branches
+        .filter(|r| !r.split(FIELD_SEP).next().unwrap_or("").ends_with("/HEAD"))
+        .map(|record| {
+            let mut parts = record.trim_start_matches('\n').split(FIELD_SEP);
             let name = parts.next().unwrap_or("").to_string();
             let sha = parts.next().unwrap_or("").to_string();
             let head_flag = parts.next().unwrap_or("");
+            let author = parts.next().unwrap_or("").to_string();
+            let committer_date_relative = parts.next().unwrap_or("").to_string();
+            let subject = parts.next().unwrap_or("").to_string();
+            let body = parts.next().unwrap_or("").trim_end_matches('\n').to_string();
             Branch {
                 name,
                 short_sha: sha,
                 is_current: head_flag == "*",
+                kind,
+                author,
+                committer_date_relative,
+                subject,
+                body,
             }
         })
         .collect();
     Ok(branches)
 }
 
-fn main() -> Result<()> {
-    let branches = load_branches()?;
-    if branches.is_empty() {
-        eprintln!("no local branches found");
-        return Ok(());
+/// Runs a git subcommand for its side effect, returning its stderr (trimmed) on failure.
+fn run_git(args: &[&str]) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
     }
+}
 
-    let mut app = App {
-        branches,
-        selected: 0,
-    };
+/// Creates a local tracking branch for a remote branch like `origin/feature` (via git's own
+/// DWIM remote-tracking resolution) and switches to it. If a local branch of that name already
+/// exists, git refuses and the failing status is returned. There's
+/// no `checkout -b` fallback here: when `switch --track` can't DWIM a local name (e.g. a
+/// conflicting local branch already exists), an explicit `-b` with that same name fails for
+/// the identical reason, so it never recovers a case the first command didn't.
+fn checkout_remote_tracking(remote_name: &str) -> Result<std::process::ExitStatus> {
+    Command::new("git").args(&["switch", "--track", remote_name]).status().map_err(Into::into)
+}
 
-    // setup terminal
+/// Runs the TUI against `out`, restoring the terminal afterwards regardless of outcome.
+fn run_tui<W: io::Write>(out: W, app: &mut App) -> Result<Option<usize>> {
+    let mut out = out;
     enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
+    execute!(out, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(out);
     let mut terminal = Terminal::new(backend)?;
 
-    let res = run_app(&mut terminal, &mut app);
+    let res = run_app(&mut terminal, app);
 
-    // teardown
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
+    res
+}
+
+fn main() -> Result<()> {
+    // --print / --no-switch: emit the picked branch name on stdout instead of switching to it,
+    // the way `gitnow` is wrapped as `choice=$(gitnow --no-shell)`. The TUI itself is drawn to
+    // stderr in this mode so stdout stays clean for the caller to capture.
+    let print_mode = std::env::args().skip(1).any(|a| a == "--print" || a == "--no-switch");
+
+    let branches = load_branches()?;
+    if branches.is_empty() {
+        eprintln!("no branches found");
+        return Ok(());
+    }
+
+    let mut app = App::new(branches, config::load());
+
+    let res = if print_mode {
+        run_tui(io::stderr(), &mut app)
+    } else {
+        run_tui(io::stdout(), &mut app)
+    };
+
+    if print_mode {
+        match res {
+            Ok(Some(idx)) => println!("{}", app.branches[idx].name),
+            Ok(None) => std::process::exit(1),
+            Err(err) => {
+                eprintln!("error: {err}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
 
     // if app ended with a selection, do the switch
     if let Ok(Some(idx)) = res {
         let branch = &app.branches[idx];
-        let status = Command::new("git")
-            .args(&["switch", &branch.name])
-            .status()?;
+        let status = match branch.kind {
+            BranchKind::Local => Command::new("git").args(&["switch", &branch.name]).status()?,
+            BranchKind::Remote => checkout_remote_tracking(&branch.name)?,
+        };
 
         // propagate failure if git switch failed
         if !status.success() {
@@ -117,44 +306,112 @@ fn run_app<B: ratatui::backend::Backend>(
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
-                .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+                .constraints(
+                    [Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)].as_ref(),
+                )
                 .split(size);
 
+            let tabs = Tabs::new(vec!["local", "remote"])
+                .select(match app.tab {
+                    BranchKind::Local => 0,
+                    BranchKind::Remote => 1,
+                })
+                .highlight_style(app.cfg.highlight_style);
+            f.render_widget(tabs, chunks[0]);
+
+            let body_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                .split(chunks[1]);
+
+            app.visible_rows = body_chunks[0].height.saturating_sub(2) as usize;
+
             let items: Vec<ListItem> = app
-                .branches
+                .visible
                 .iter()
-                .enumerate()
-                .map(|(i, b)| {
+                .map(|&real_idx| {
+                    let b = &app.branches[real_idx];
                     let marker = if b.is_current { "*" } else { " " };
-                    let prefix = if i == app.selected { ">" } else { " " };
                     let content = Line::from(vec![
-                        Span::raw(format!("{prefix}{marker} ")),
-                        Span::styled(&b.name, Style::default().add_modifier(
-                            if i == app.selected {
-                                Modifier::BOLD
-                            } else {
-                                Modifier::empty()
-                            },
-                        )),
+                        Span::raw(format!("{marker} ")),
+                        Span::raw(&b.name),
                         Span::raw(format!("  {}", b.short_sha)),
                     ]);
                     ListItem::new(content)
                 })
                 .collect();
 
+            let title = match app.mode {
+                Mode::Filter => format!("branches (filter: {}_)", app.filter),
+                _ if !app.filter.is_empty() => format!("branches (filter: {})", app.filter),
+                _ => "branches".to_string(),
+            };
             let list = List::new(items)
-                .block(Block::default().borders(Borders::ALL).title("branches"))
-                .highlight_symbol("> ");
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .highlight_symbol("> ")
+                .highlight_style(app.cfg.highlight_style)
+                .scroll_padding(1);
 
-            f.render_widget(list, chunks[0]);
+            app.list_state.select(if app.visible.is_empty() { None } else { Some(app.selected) });
+            f.render_stateful_widget(list, body_chunks[0], &mut app.list_state);
+
+            let current_idx = app.visible.get(app.selected).copied();
+            if current_idx != app.last_previewed {
+                app.preview_scroll = 0;
+                app.last_previewed = current_idx;
+            }
 
-            let help = Line::from(vec![Span::raw(
-                "j/k or ↑/↓ to move, enter to switch, q to quit",
-            )]);
+            let preview = app
+                .selected_branch()
+                .map(|b| {
+                    format!(
+                        "{}\n{}\n\n{}\n\n{}",
+                        b.author, b.committer_date_relative, b.subject, b.body
+                    )
+                })
+                .unwrap_or_default();
+            let preview_block = Block::default().borders(Borders::ALL).title("commit");
+            let preview_paragraph = ratatui::widgets::Paragraph::new(preview)
+                .block(preview_block)
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .scroll((app.preview_scroll, 0));
+            f.render_widget(preview_paragraph, body_chunks[1]);
+
+            let selected_name = app.selected_branch().map(|b| b.name.as_str()).unwrap_or("");
+            // Mode-specific prompts (in particular the force-delete confirmation) always take
+            // priority over a stale `app.error` left over from the git failure that triggered
+            // them -- otherwise the user sees only the raw git stderr with no indication that
+            // pressing `y` is now armed to force-delete the branch.
+            let help = match &app.mode {
+                Mode::Filter => "type to filter, enter to switch, esc to cancel".to_string(),
+                Mode::Normal => {
+                    if let Some(err) = &app.error {
+                        format!("error: {err}")
+                    } else {
+                        "j/k or ↑/↓ to move, g/G top/bottom, PgUp/PgDn page, ctrl-u/ctrl-d \
+                            scroll preview, tab to switch local/remote, / to filter, n new, \
+                            r rename, d delete, enter to switch, q to quit"
+                            .to_string()
+                    }
+                }
+                Mode::Prompt(PromptAction::Create) => {
+                    format!("new branch name (from {selected_name}): {}_", app.prompt_input)
+                }
+                Mode::Prompt(PromptAction::Rename) => {
+                    format!("rename '{selected_name}' to: {}_", app.prompt_input)
+                }
+                Mode::ConfirmDelete { force: false } => {
+                    format!("delete branch '{selected_name}'? (y/n)")
+                }
+                Mode::ConfirmDelete { force: true } => {
+                    format!("'{selected_name}' isn't fully merged, force delete? (y/n)")
+                }
+            };
+            let help = Line::from(vec![Span::raw(help)]);
             let help_block = Block::default().title("help").borders(Borders::TOP);
             let paragraph =
                 ratatui::widgets::Paragraph::new(help).block(help_block).alignment(Alignment::Left);
-            f.render_widget(paragraph, chunks[1]);
+            f.render_widget(paragraph, chunks[2]);
         })?;
 
         if event::poll(std::time::Duration::from_millis(250))? {
@@ -164,20 +421,161 @@ fn run_app<B: ratatui::backend::Backend>(
                     continue;
                 }
 
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        if app.selected + 1 < app.branches.len() {
-                            app.selected += 1;
+                match app.mode {
+                    Mode::Filter => match key.code {
+                        KeyCode::Esc => {
+                            app.filter.clear();
+                            app.mode = Mode::Normal;
+                            app.recompute_filter();
                         }
-                    }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if app.selected > 0 {
-                            app.selected -= 1;
+                        KeyCode::Backspace => {
+                            app.filter.pop();
+                            app.recompute_filter();
+                        }
+                        KeyCode::Enter => {
+                            if let Some(&real_idx) = app.visible.get(app.selected) {
+                                return Ok(Some(real_idx));
+                            }
+                        }
+                        KeyCode::Char(c) => {
+                            app.filter.push(c);
+                            app.recompute_filter();
+                        }
+                        _ => {}
+                    },
+                    Mode::Normal => {
+                        app.error = None;
+                        // Esc always clears an active filter before it's allowed to quit,
+                        // regardless of what `quit` is bound to.
+                        if key.code == KeyCode::Esc && !app.filter.is_empty() {
+                            app.filter.clear();
+                            app.recompute_filter();
+                        } else if let Some(action) = app.cfg.keys.resolve(key.code, key.modifiers) {
+                            match action {
+                                Action::Quit => return Ok(None),
+                                Action::Filter => {
+                                    app.mode = Mode::Filter;
+                                }
+                                Action::Tab => {
+                                    app.toggle_tab();
+                                }
+                                Action::Create if app.tab == BranchKind::Local => {
+                                    app.prompt_input.clear();
+                                    app.mode = Mode::Prompt(PromptAction::Create);
+                                }
+                                Action::Rename if app.tab == BranchKind::Local => {
+                                    app.prompt_input.clear();
+                                    app.mode = Mode::Prompt(PromptAction::Rename);
+                                }
+                                Action::Delete
+                                    if app.tab == BranchKind::Local
+                                        && app.selected_branch().is_some() =>
+                                {
+                                    app.mode = Mode::ConfirmDelete { force: false };
+                                }
+                                Action::Down => {
+                                    if app.selected + 1 < app.visible.len() {
+                                        app.selected += 1;
+                                    }
+                                }
+                                Action::Up => {
+                                    if app.selected > 0 {
+                                        app.selected -= 1;
+                                    }
+                                }
+                                Action::PageDown => {
+                                    app.selected = (app.selected + app.visible_rows)
+                                        .min(app.visible.len().saturating_sub(1));
+                                }
+                                Action::PageUp => {
+                                    app.selected = app.selected.saturating_sub(app.visible_rows);
+                                }
+                                Action::Top => {
+                                    app.selected = 0;
+                                }
+                                Action::Bottom => {
+                                    app.selected = app.visible.len().saturating_sub(1);
+                                }
+                                Action::PreviewUp => {
+                                    app.preview_scroll = app.preview_scroll.saturating_sub(3);
+                                }
+                                Action::PreviewDown => {
+                                    app.preview_scroll = app.preview_scroll.saturating_add(3);
+                                }
+                                Action::Switch => {
+                                    if let Some(&real_idx) = app.visible.get(app.selected) {
+                                        return Ok(Some(real_idx));
+                                    }
+                                }
+                                Action::Create | Action::Rename | Action::Delete => {}
+                            }
                         }
                     }
-                    KeyCode::Enter => return Ok(Some(app.selected)),
-                    _ => {}
+                    Mode::Prompt(action) => match key.code {
+                        KeyCode::Esc => {
+                            app.mode = Mode::Normal;
+                        }
+                        KeyCode::Backspace => {
+                            app.prompt_input.pop();
+                        }
+                        KeyCode::Enter => {
+                            let new_name = app.prompt_input.trim().to_string();
+                            if let (false, Some(base_name)) = (
+                                new_name.is_empty(),
+                                app.selected_branch().map(|b| b.name.clone()),
+                            ) {
+                                let result = match action {
+                                    PromptAction::Create => {
+                                        run_git(&["branch", &new_name, &base_name])
+                                    }
+                                    PromptAction::Rename => {
+                                        run_git(&["branch", "-m", &base_name, &new_name])
+                                    }
+                                };
+                                match result {
+                                    Ok(()) => app.reload()?,
+                                    Err(stderr) => app.error = Some(stderr),
+                                }
+                            }
+                            app.mode = Mode::Normal;
+                        }
+                        KeyCode::Char(c) => {
+                            app.prompt_input.push(c);
+                        }
+                        _ => {}
+                    },
+                    Mode::ConfirmDelete { force } => match key.code {
+                        KeyCode::Char('y') | KeyCode::Enter => {
+                            if let Some(name) = app.selected_branch().map(|b| b.name.clone()) {
+                                let flag = if force { "-D" } else { "-d" };
+                                match run_git(&["branch", flag, &name]) {
+                                    Ok(()) => {
+                                        app.reload()?;
+                                        app.mode = Mode::Normal;
+                                    }
+                                    Err(stderr) => {
+                                        // Only arm the force-delete prompt when git's failure is
+                                        // actually the "not fully merged" case; any other failure
+                                        // (e.g. deleting the checked-out branch) isn't fixed by
+                                        // `-D` and would make the confirm text lie about why.
+                                        let not_merged = stderr.contains("not fully merged");
+                                        app.error = Some(stderr);
+                                        app.mode = if !force && not_merged {
+                                            Mode::ConfirmDelete { force: true }
+                                        } else {
+                                            Mode::Normal
+                                        };
+                                    }
+                                }
+                            } else {
+                                app.mode = Mode::Normal;
+                            }
+                        }
+                        KeyCode::Char('n') | KeyCode::Esc => {
+                            app.mode = Mode::Normal;
+                        }
+                        _ => {}
+                    },
                 }
             }
         }